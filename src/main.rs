@@ -23,13 +23,13 @@ fn main() -> Result<(), eyre::Error> {
         .with_context(|| format!("Can't read {}", opt.config.display()))?;
 
     let Config {
-        host_whitelist,
+        mut host_whitelist,
         host_blacklist,
         blocklists,
     } = serde_json::from_str(&config)
         .with_context(|| format!("Can't parse {}", opt.config.display()))?;
 
-    let mut merged = host_blacklist;
+    let mut merged = host_blacklist.clone();
 
     let mut cache = Cache::new(opt.cache.clone());
 
@@ -54,13 +54,24 @@ fn main() -> Result<(), eyre::Error> {
             }
         };
 
-        for host in parse_blocklist(&hosts) {
-            match host {
-                Ok(host) => {
+        for entry in parse_blocklist(&hosts) {
+            match entry {
+                Ok(BlocklistEntry::Block(host)) => {
                     if !host_whitelist.contains(&host) {
                         merged.insert(host);
                     }
                 }
+                Ok(BlocklistEntry::Exception(host)) => {
+                    if host_blacklist.contains(&host) {
+                        warn!(
+                            "In blocklist {}: exception for {} ignored, host is in host_blacklist",
+                            blocklist_url, host.0
+                        );
+                    } else {
+                        merged.remove(&host);
+                        host_whitelist.insert(host);
+                    }
+                }
                 Err(e) => {
                     warn!("In blocklist {}: {}", blocklist_url, e);
                     failed = true;
@@ -188,7 +199,51 @@ impl std::str::FromStr for BlocklistOutput {
     }
 }
 
-fn parse_blocklist<'a>(blocklist: &'a str) -> impl Iterator<Item = Result<Host, eyre::Error>> + 'a {
+/// An entry extracted from a blocklist: either a host to block or, in
+/// Adblock Plus lists, an exception that cancels a block elsewhere.
+enum BlocklistEntry {
+    Block(Host),
+    Exception(Host),
+}
+
+/// The syntax a blocklist is written in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Dialect {
+    /// `0.0.0.0 example.com` or plain `example.com` per line, `#` comments.
+    Hosts,
+    /// Adblock Plus network-filter syntax (`||example.com^`), `!` comments.
+    Abp,
+}
+
+/// Guesses a blocklist's dialect by sampling its first non-comment lines
+/// for Adblock Plus network-filter syntax (`||`/`@@` rules).
+fn detect_dialect(blocklist: &str) -> Dialect {
+    let is_abp = blocklist
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('!'))
+        .take(20)
+        .any(|line| line.starts_with("||") || line.starts_with("@@"));
+
+    if is_abp {
+        Dialect::Abp
+    } else {
+        Dialect::Hosts
+    }
+}
+
+fn parse_blocklist<'a>(
+    blocklist: &'a str,
+) -> Box<dyn Iterator<Item = Result<BlocklistEntry, eyre::Error>> + 'a> {
+    match detect_dialect(blocklist) {
+        Dialect::Hosts => Box::new(parse_hosts_blocklist(blocklist)),
+        Dialect::Abp => Box::new(parse_abp_blocklist(blocklist)),
+    }
+}
+
+fn parse_hosts_blocklist<'a>(
+    blocklist: &'a str,
+) -> impl Iterator<Item = Result<BlocklistEntry, eyre::Error>> + 'a {
     static HOST_REGEX: Lazy<Regex> =
         Lazy::new(|| Regex::new(r#"^\s*((?P<ip>\S+)\s+)?(?P<host>\S+)\s*$"#).unwrap());
     static COMMENT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new("#.*").unwrap());
@@ -212,7 +267,7 @@ fn parse_blocklist<'a>(blocklist: &'a str) -> impl Iterator<Item = Result<Host,
                     None
                 } else {
                     let host = captures.name("host").unwrap().as_str();
-                    Some(Host::try_from(host.to_owned()))
+                    Some(Host::try_from(host.to_owned()).map(BlocklistEntry::Block))
                 }
             }
             None => Some(Err(eyre::format_err!(
@@ -222,7 +277,40 @@ fn parse_blocklist<'a>(blocklist: &'a str) -> impl Iterator<Item = Result<Host,
         })
 }
 
-#[derive(Ord, PartialOrd, Eq, PartialEq)]
+/// Parses Adblock Plus network-filter syntax: `||example.com^` blocks
+/// `example.com` (and implicitly its subdomains), `@@||example.com^` is an
+/// exception that cancels such a block. `$`-options are ignored. `!` starts
+/// a comment. Rules this function can't make sense of (element-hiding
+/// rules, path components, mid-pattern wildcards, ...) are logged and
+/// skipped rather than treated as a parse error, since ABP lists routinely
+/// contain rule kinds we have no use for.
+fn parse_abp_blocklist<'a>(
+    blocklist: &'a str,
+) -> impl Iterator<Item = Result<BlocklistEntry, eyre::Error>> + 'a {
+    static BLOCK_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^\|\|(?P<host>[^\^/\$\*]+)(?:[\^\$]|$)").unwrap());
+    static EXCEPTION_REGEX: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^@@\|\|(?P<host>[^\^/\$\*]+)(?:[\^\$]|$)").unwrap());
+
+    blocklist
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('!'))
+        .filter_map(|line| {
+            if let Some(captures) = EXCEPTION_REGEX.captures(line) {
+                let host = captures.name("host").unwrap().as_str();
+                Some(Host::try_from(host.to_owned()).map(BlocklistEntry::Exception))
+            } else if let Some(captures) = BLOCK_REGEX.captures(line) {
+                let host = captures.name("host").unwrap().as_str();
+                Some(Host::try_from(host.to_owned()).map(BlocklistEntry::Block))
+            } else {
+                warn!("Skipping unrecognized Adblock Plus rule \"{}\"", line);
+                None
+            }
+        })
+}
+
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq)]
 struct Host(String);
 
 impl TryFrom<String> for Host {
@@ -293,4 +381,46 @@ mod tests {
         assert!(Host::try_from("fi sh".to_owned()).is_err());
         assert!(Host::try_from("".to_owned()).is_err());
     }
+
+    #[test]
+    fn detects_abp_dialect() {
+        assert_eq!(detect_dialect("0.0.0.0 example.com"), Dialect::Hosts);
+        assert_eq!(
+            detect_dialect("! Title: Some list\n||example.com^"),
+            Dialect::Abp
+        );
+    }
+
+    #[test]
+    fn parse_abp_blocklist_extracts_blocks_and_exceptions() {
+        let blocklist = concat!(
+            "! comment\n",
+            "||ads.example.com^\n",
+            "@@||good.example.com^$important\n",
+            "##.ad-banner\n",
+            "||*.wildcard.example.com^\n",
+        );
+
+        let entries: Vec<BlocklistEntry> = parse_abp_blocklist(blocklist)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert!(matches!(
+            &entries[..],
+            [BlocklistEntry::Block(a), BlocklistEntry::Exception(b)]
+                if a.0 == "ads.example.com" && b.0 == "good.example.com"
+        ));
+    }
+
+    #[test]
+    fn parse_abp_blocklist_accepts_bare_domain_rule() {
+        let entries: Vec<BlocklistEntry> = parse_abp_blocklist("||example.com")
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert!(matches!(
+            &entries[..],
+            [BlocklistEntry::Block(a)] if a.0 == "example.com"
+        ));
+    }
 }